@@ -1,20 +1,26 @@
 use std::any::Any;
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_query::logical_plan::Expr;
-use common_recordbatch::SendableRecordBatchStream;
-use common_telemetry::debug;
+use common_recordbatch::{RecordBatch, SendableRecordBatchStream};
+use common_telemetry::{debug, error};
 use common_time::timestamp::Timestamp;
 use common_time::util;
 use datatypes::prelude::{ConcreteDataType, ScalarVector};
 use datatypes::schema::{ColumnSchema, Schema, SchemaBuilder, SchemaRef};
-use datatypes::vectors::{BinaryVector, TimestampVector, UInt8Vector};
+use datatypes::value::Value;
+use datatypes::vectors::{BinaryVector, TimestampVector, UInt8Vector, Vector};
+use futures::TryStreamExt;
 use serde::{Deserialize, Serialize};
 use snafu::{ensure, OptionExt, ResultExt};
 use table::engine::{EngineContext, TableEngineRef};
 use table::metadata::TableId;
-use table::requests::{CreateTableRequest, InsertRequest, OpenTableRequest};
+use table::requests::{
+    AddColumnRequest, AlterKind, AlterTableRequest, CreateTableRequest, DeleteRequest,
+    InsertRequest, OpenTableRequest,
+};
 use table::{Table, TableRef};
 
 use crate::consts::{
@@ -22,18 +28,71 @@ use crate::consts::{
     SYSTEM_CATALOG_TABLE_NAME,
 };
 use crate::error::{
-    CreateSystemCatalogSnafu, EmptyValueSnafu, Error, InvalidEntryTypeSnafu, InvalidKeySnafu,
-    OpenSystemCatalogSnafu, Result, ValueDeserializeSnafu,
+    CreateSystemCatalogSnafu, EmptyValueSnafu, Error, IcebergRestSnafu, InvalidEntryTypeSnafu,
+    InvalidKeySnafu, MigrateSystemCatalogSnafu, OpenSystemCatalogSnafu, Result,
+    ValueDeserializeSnafu,
 };
 
 pub const ENTRY_TYPE_INDEX: usize = 0;
 pub const KEY_INDEX: usize = 1;
 pub const TIMESTAMP_INDEX: usize = 2;
 pub const VALUE_INDEX: usize = 3;
+pub const OP_INDEX: usize = 4;
+
+/// Marks a dictionary-encoded component (see [EntryType::NameDictionary]) of
+/// a table key, e.g. `$0.$1.my_table`. Without an explicit tag, a component
+/// couldn't be told apart from a literal catalog/schema name that happens to
+/// be all-digits (e.g. a numeric multi-tenant catalog id) by parsing alone,
+/// so [resolve_dictionary_ids] would silently swap such a name for whatever
+/// unrelated name the dictionary has under the same id. `$` is not a valid
+/// character in a catalog/schema identifier, so it is safe to reserve.
+const DICTIONARY_ID_PREFIX: char = '$';
+
+/// Current on-disk format version of the system catalog table.
+///
+/// Bump this whenever the key/value encoding of an [EntryType] changes, and
+/// append the corresponding migration to [MIGRATIONS] so that catalogs
+/// created by older versions keep decoding correctly.
+pub const CURRENT_CATALOG_VERSION: u32 = 3;
+
+/// A migration rewrites the decoded entry stream of a catalog from one
+/// format version to the next. `MIGRATIONS[v]` migrates a catalog at
+/// version `v` up to version `v + 1`.
+type Migration = fn(&mut Vec<Entry>) -> Result<()>;
+
+/// Migrations are indexed by the version they migrate *from*. There is no
+/// migration away from [CURRENT_CATALOG_VERSION] yet.
+const MIGRATIONS: &[Migration] = &[
+    // v0 -> v1: introduces the version row itself. Existing rows need no
+    // rewriting, since the key/value encoding for catalog/schema/table
+    // entries is unchanged.
+    |_entries| Ok(()),
+    // v1 -> v2: dictionary-encodes the catalog/schema components of table
+    // keys (see `SystemCatalogTable::insert_table`). The decoded `Entry`
+    // shape is unaffected, since `decode_entries` already resolves
+    // dictionary ids back to names; re-inserting through
+    // `SystemCatalogTable::insert_entry` is what actually upgrades each
+    // table row's on-disk key to the dictionary-encoded form.
+    |_entries| Ok(()),
+    // v2 -> v3: enriches `TableEntryValue` with the full schema, primary key
+    // indices and table options (see `TableEntry::schema`), and adds
+    // per-table schema-evolution history. Every new field carries
+    // `#[serde(default)]`, so a pre-v3 row (just `{"table_id": ...}`)
+    // already decodes safely with `schema: None` and empty
+    // `primary_key_indices`/`table_options` - there is nothing this
+    // migration needs to rewrite. Callers can backfill the new fields for
+    // an existing table via `SystemCatalogTable::alter_table`.
+    |_entries| Ok(()),
+];
 
 pub struct SystemCatalogTable {
     schema: SchemaRef,
     pub table: TableRef,
+    /// Serializes [Self::intern], since interning is a read-the-max-then-
+    /// insert-the-next-id sequence with no compare-and-swap primitive
+    /// backing it. Without this, two concurrent calls interning different
+    /// new names could both read the same max id and mint a duplicate.
+    intern_lock: tokio::sync::Mutex<()>,
 }
 
 #[async_trait::async_trait]
@@ -59,6 +118,11 @@ impl Table for SystemCatalogTable {
     async fn insert(&self, request: InsertRequest) -> table::error::Result<usize> {
         self.table.insert(request).await
     }
+
+    /// Delete rows from table, by primary key.
+    async fn delete(&self, request: DeleteRequest) -> table::error::Result<usize> {
+        self.table.delete(request).await
+    }
 }
 
 impl SystemCatalogTable {
@@ -77,7 +141,15 @@ impl SystemCatalogTable {
             .await
             .context(OpenSystemCatalogSnafu)?
         {
-            Ok(Self { table, schema })
+            let table = Self::ensure_op_column(&engine, &ctx, table).await?;
+            let catalog = Self {
+                table,
+                schema,
+                intern_lock: tokio::sync::Mutex::new(()),
+            };
+            catalog.migrate_if_needed().await?;
+            catalog.spawn_background_compaction();
+            Ok(catalog)
         } else {
             // system catalog table is not yet created, try to create
             let request = CreateTableRequest {
@@ -96,24 +168,763 @@ impl SystemCatalogTable {
                 .create_table(&ctx, request)
                 .await
                 .context(CreateSystemCatalogSnafu)?;
-            Ok(Self { table, schema })
+            let catalog = Self {
+                table,
+                schema,
+                intern_lock: tokio::sync::Mutex::new(()),
+            };
+            // A freshly created catalog starts out at the current version, so
+            // there is nothing to migrate.
+            catalog.write_version(CURRENT_CATALOG_VERSION).await?;
+            catalog.spawn_background_compaction();
+            Ok(catalog)
+        }
+    }
+
+    /// Adds the `op` column introduced alongside MVCC tombstones to a system
+    /// catalog table that was physically created before that change. A
+    /// freshly created table already has the column, since
+    /// [build_system_catalog_schema] includes it; this only matters for
+    /// `open_table` against a pre-existing one.
+    async fn ensure_op_column(
+        engine: &TableEngineRef,
+        ctx: &EngineContext,
+        table: TableRef,
+    ) -> Result<TableRef> {
+        if table.schema().column_index_by_name("op").is_some() {
+            return Ok(table);
+        }
+
+        let request = AlterTableRequest {
+            catalog_name: SYSTEM_CATALOG_NAME.to_string(),
+            schema_name: INFORMATION_SCHEMA_NAME.to_string(),
+            table_name: SYSTEM_CATALOG_TABLE_NAME.to_string(),
+            alter_kind: AlterKind::AddColumns {
+                columns: vec![AddColumnRequest {
+                    // Nullable, unlike the `op` column of a freshly created
+                    // table: there is no default to backfill existing rows
+                    // with, and `RawRow::from_values` already treats a
+                    // missing/null op as `Op::Upsert`, which is the correct
+                    // reading for every row written before tombstones
+                    // existed.
+                    column_schema: ColumnSchema::new(
+                        "op".to_string(),
+                        ConcreteDataType::uint8_datatype(),
+                        true,
+                    ),
+                    is_key: false,
+                }],
+            },
+        };
+        engine
+            .alter_table(ctx, request)
+            .await
+            .context(MigrateSystemCatalogSnafu)
+    }
+
+    /// How often background compaction runs to physically drop superseded
+    /// and tombstoned rows. See [Self::compact].
+    const COMPACTION_INTERVAL: Duration = Duration::from_secs(300);
+
+    /// Spawns the background task that periodically calls [Self::compact].
+    /// Runs for the lifetime of the process; there is no explicit shutdown
+    /// hook, matching this table's append-mostly lifecycle (it is only ever
+    /// dropped when the whole engine shuts down).
+    fn spawn_background_compaction(&self) {
+        let table = self.table.clone();
+        common_runtime::spawn_bg(async move {
+            let mut interval = tokio::time::interval(Self::COMPACTION_INTERVAL);
+            loop {
+                interval.tick().await;
+                if let Err(e) = compact_table(&table).await {
+                    error!(e; "Failed to run system catalog background compaction");
+                }
+            }
+        });
+    }
+
+    /// Returns every live entry in the system catalog table: the public read
+    /// path consumers use to load it, after the same MVCC reconciliation
+    /// (drop superseded and tombstoned rows, resolve dictionary-encoded
+    /// keys) that the internal readers below already apply. See
+    /// [Self::decode_entries].
+    pub async fn records(&self) -> Result<Vec<Entry>> {
+        self.decode_entries().await
+    }
+
+    /// Reads the catalog format version stored in the table, treating a
+    /// missing version row as version 0 (i.e. a catalog created before this
+    /// versioning scheme existed).
+    async fn read_version(&self) -> Result<u32> {
+        let rows = self.raw_rows().await?;
+        let survivor = reconcile(rows)
+            .into_iter()
+            .find(|row| row.entry_type == EntryType::Version as u8);
+        match survivor {
+            Some(row) => {
+                let value = row.value.context(EmptyValueSnafu)?;
+                let version: VersionEntry =
+                    serde_json::from_slice(&value).context(ValueDeserializeSnafu)?;
+                Ok(version.version)
+            }
+            None => Ok(0),
+        }
+    }
+
+    /// Writes (or overwrites) the stored catalog format version.
+    async fn write_version(&self, version: u32) -> Result<()> {
+        self.table
+            .insert(build_version_insert_request(version))
+            .await
+            .context(MigrateSystemCatalogSnafu)?;
+        Ok(())
+    }
+
+    /// Reads the stored catalog version and, if it is older than
+    /// [CURRENT_CATALOG_VERSION], runs the pending migrations over the
+    /// decoded entry stream, re-inserts the rewritten rows and bumps the
+    /// stored version.
+    async fn migrate_if_needed(&self) -> Result<()> {
+        let stored_version = self.read_version().await?;
+        if stored_version >= CURRENT_CATALOG_VERSION {
+            return Ok(());
+        }
+
+        let mut entries = self.decode_entries().await?;
+        for version in stored_version..CURRENT_CATALOG_VERSION {
+            let migration = MIGRATIONS[version as usize];
+            migration(&mut entries)?;
+        }
+
+        for entry in &entries {
+            self.insert_entry(entry).await?;
+        }
+        // Bumping the version last makes the migration effectively
+        // transactional: a crash mid-migration just re-runs it on restart.
+        self.write_version(CURRENT_CATALOG_VERSION).await?;
+        Ok(())
+    }
+
+    /// Decodes every live (non-tombstoned, non-bookkeeping) row currently in
+    /// the catalog into [Entry], after reconciling MVCC row versions and
+    /// resolving any dictionary-encoded table keys back to their names.
+    ///
+    /// Unlike other entry types, table rows are *not* reconciled down to a
+    /// single survivor: every non-tombstoned version sharing a `(entry_type,
+    /// key)` is kept, with the highest-timestamp row decoded as the current
+    /// [TableEntry] and the rest exposed through [TableEntry::history]. See
+    /// [SystemCatalogTable::alter_table].
+    async fn decode_entries(&self) -> Result<Vec<Entry>> {
+        let rows = self.raw_rows().await?;
+        let dictionary = self.dictionary().await?;
+
+        let mut table_versions: HashMap<Vec<u8>, Vec<RawRow>> = HashMap::new();
+        let mut other_rows = Vec::new();
+        for row in rows {
+            if row.entry_type == EntryType::Table as u8 {
+                table_versions.entry(row.key.clone()).or_default().push(row);
+            } else {
+                other_rows.push(row);
+            }
+        }
+
+        let mut entries = Vec::new();
+        for (_, versions) in table_versions {
+            if let Some(table) = decode_table_versions(versions)? {
+                entries.push(resolve_dictionary_ids(Entry::Table(table), &dictionary));
+            }
+        }
+
+        for row in reconcile(other_rows) {
+            if row.entry_type == EntryType::Version as u8
+                || row.entry_type == EntryType::NameDictionary as u8
+            {
+                continue;
+            }
+            let entry =
+                decode_system_catalog(Some(row.entry_type), Some(&row.key), row.value.as_deref())?;
+            entries.push(resolve_dictionary_ids(entry, &dictionary));
+        }
+
+        Ok(entries)
+    }
+
+    /// Re-inserts a decoded [Entry], routing table entries through
+    /// [Self::insert_table_version_at] so that their keys stay
+    /// dictionary-encoded. Every history snapshot is carried across to the
+    /// new key too, not just the current one.
+    ///
+    /// A table entry decoded before this upgrade was keyed by its literal
+    /// dotted name (`<catalog>.<schema>.<table>`), which is a *different*
+    /// `(entry_type, key)` primary key group than the dictionary-encoded key
+    /// `insert_table_version_at` now writes under. Re-inserting only the
+    /// current snapshot under the new key and then tombstoning the old
+    /// literal-key group would permanently delete any history still riding
+    /// on that old key, since [decode_table_versions] treats a
+    /// tombstone-latest group as wholly gone. So every row in
+    /// [TableEntry::history] is re-inserted under the new key first, oldest
+    /// first; only once nothing of value is left under the old key is it
+    /// tombstoned. This is a no-op tombstone (over an already-empty key
+    /// group) for any table that was already dictionary-encoded.
+    async fn insert_entry(&self, entry: &Entry) -> Result<()> {
+        if let Entry::Table(e) = entry {
+            // History rows carry no original timestamp once decoded into
+            // `TableEntryValue` - only their relative order (oldest to
+            // newest, current last) matters, so this mints fresh strictly
+            // increasing ones rather than reusing `e`'s original timestamp.
+            let base = util::current_time_millis() - (e.history.len() as i64 + 1);
+            for (i, meta) in e.history.iter().enumerate() {
+                self.insert_table_version_at(
+                    &e.catalog_name,
+                    &e.schema_name,
+                    &e.table_name,
+                    meta.clone(),
+                    base + i as i64,
+                )
+                .await?;
+            }
+
+            let meta = TableEntryValue {
+                table_id: e.table_id,
+                schema_version: e.schema_version,
+                schema: e.schema.clone(),
+                primary_key_indices: e.primary_key_indices.clone(),
+                table_options: e.table_options.clone(),
+            };
+            self.insert_table_version_at(
+                &e.catalog_name,
+                &e.schema_name,
+                &e.table_name,
+                meta,
+                base + e.history.len() as i64,
+            )
+            .await?;
+
+            let literal_key = format!("{}.{}.{}", e.catalog_name, e.schema_name, e.table_name);
+            self.table
+                .insert(build_table_delete_request(literal_key))
+                .await
+                .context(MigrateSystemCatalogSnafu)?;
+            return Ok(());
+        }
+        self.table
+            .insert(build_entry_insert_request(entry))
+            .await
+            .context(MigrateSystemCatalogSnafu)?;
+        Ok(())
+    }
+
+    /// Inserts the first catalog row for a newly created table, at
+    /// `schema_version` 0. Takes the same [CreateTableRequest] passed to the
+    /// table engine, rather than its `catalog_name`/`schema_name`/`schema`/
+    /// `primary_key_indices`/`table_options` fields individually.
+    pub async fn insert_table(&self, request: &CreateTableRequest) -> Result<()> {
+        let catalog_name = request.catalog_name.as_deref().unwrap_or_default();
+        let schema_name = request.schema_name.as_deref().unwrap_or_default();
+        let meta = TableEntryValue {
+            table_id: request.id,
+            schema_version: 0,
+            schema: Some(request.schema.clone()),
+            primary_key_indices: request.primary_key_indices.clone(),
+            table_options: request.table_options.clone(),
+        };
+        self.insert_table_version(catalog_name, schema_name, &request.table_name, meta)
+            .await
+    }
+
+    /// Appends a new schema snapshot for an existing table, as an ALTER
+    /// would: writes a new row sharing the table's `(entry_type, key)` with a
+    /// fresher timestamp and `schema_version` one past whatever is currently
+    /// stored, rather than overwriting it. The previous snapshots remain
+    /// readable through [TableEntry::history].
+    ///
+    /// Takes a [CreateTableRequest] for the same reason as [Self::insert_table];
+    /// its `id` field is ignored, since a table's id cannot change across an
+    /// ALTER.
+    pub async fn alter_table(&self, request: &CreateTableRequest) -> Result<()> {
+        let catalog_name = request.catalog_name.as_deref().unwrap_or_default();
+        let schema_name = request.schema_name.as_deref().unwrap_or_default();
+        let current = self
+            .table_entry(catalog_name, schema_name, &request.table_name)
+            .await?
+            .context(InvalidKeySnafu {
+                key: Some(request.table_name.clone()),
+            })?;
+        let meta = TableEntryValue {
+            table_id: current.table_id,
+            schema_version: current.schema_version + 1,
+            schema: Some(request.schema.clone()),
+            primary_key_indices: request.primary_key_indices.clone(),
+            table_options: request.table_options.clone(),
+        };
+        self.insert_table_version(catalog_name, schema_name, &request.table_name, meta)
+            .await
+    }
+
+    /// Looks up the current catalog row for a table, if any, including its
+    /// schema-evolution history. See [TableEntry::history].
+    pub async fn table_entry(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<Option<TableEntry>> {
+        let entries = self.decode_entries().await?;
+        Ok(entries.into_iter().find_map(|entry| match entry {
+            Entry::Table(table)
+                if table.catalog_name == catalog_name
+                    && table.schema_name == schema_name
+                    && table.table_name == table_name =>
+            {
+                Some(table)
+            }
+            _ => None,
+        }))
+    }
+
+    /// Interns `catalog_name` and `schema_name` into the name dictionary (see
+    /// [EntryType::NameDictionary]) and writes `meta` as a table row keyed by
+    /// their small integer ids plus the table name, instead of the full
+    /// dotted name. This is what keeps the system catalog small when many
+    /// tables share a handful of catalogs/schemas.
+    async fn insert_table_version(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        meta: TableEntryValue,
+    ) -> Result<()> {
+        self.insert_table_version_at(
+            catalog_name,
+            schema_name,
+            table_name,
+            meta,
+            util::current_time_millis(),
+        )
+        .await
+    }
+
+    /// As [Self::insert_table_version], but with an explicit row timestamp
+    /// instead of the current time. Used by [Self::insert_entry] to backfill
+    /// a table's schema-evolution history under a migrated key with
+    /// timestamps that preserve the snapshots' original relative order.
+    async fn insert_table_version_at(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+        meta: TableEntryValue,
+        timestamp_millis: i64,
+    ) -> Result<()> {
+        let catalog_id = self.intern(catalog_name).await?;
+        let schema_id = self.intern(schema_name).await?;
+        let key = format!(
+            "{p}{}.{p}{}.{}",
+            catalog_id,
+            schema_id,
+            table_name,
+            p = DICTIONARY_ID_PREFIX
+        );
+        self.table
+            .insert(build_table_insert_request_at(key, &meta, timestamp_millis))
+            .await
+            .context(MigrateSystemCatalogSnafu)?;
+        Ok(())
+    }
+
+    /// Writes a tombstone for a table previously inserted via
+    /// [Self::insert_table]. A no-op if `catalog_name`/`schema_name` were
+    /// never interned, since no matching row could exist.
+    pub async fn delete_table(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        table_name: &str,
+    ) -> Result<()> {
+        let (catalog_id, schema_id) = match (
+            self.dictionary_id(catalog_name).await?,
+            self.dictionary_id(schema_name).await?,
+        ) {
+            (Some(catalog_id), Some(schema_id)) => (catalog_id, schema_id),
+            _ => return Ok(()),
+        };
+        let key = format!(
+            "{p}{}.{p}{}.{}",
+            catalog_id,
+            schema_id,
+            table_name,
+            p = DICTIONARY_ID_PREFIX
+        );
+        self.table
+            .insert(build_table_delete_request(key))
+            .await
+            .context(MigrateSystemCatalogSnafu)?;
+        Ok(())
+    }
+
+    /// Returns the id `name` is already interned under, if any, without
+    /// interning it.
+    async fn dictionary_id(&self, name: &str) -> Result<Option<u32>> {
+        let dictionary = self.dictionary().await?;
+        Ok(dictionary
+            .into_iter()
+            .find(|(_, existing)| existing == name)
+            .map(|(id, _)| id))
+    }
+
+    /// Returns the id `name` is interned under, interning it with a fresh id
+    /// if this is the first time it is seen.
+    ///
+    /// Reading the current max id and writing the next one is not atomic at
+    /// the storage layer, so this serializes on [Self::intern_lock] for the
+    /// whole read-then-write sequence: without it, two concurrent calls
+    /// interning two different new names could both read the same max and
+    /// mint the same id.
+    async fn intern(&self, name: &str) -> Result<u32> {
+        let _guard = self.intern_lock.lock().await;
+
+        let dictionary = self.dictionary().await?;
+        if let Some((&id, _)) = dictionary.iter().find(|(_, existing)| existing.as_str() == name)
+        {
+            return Ok(id);
+        }
+
+        let id = dictionary.keys().max().map_or(0, |max| max + 1);
+        let value = serde_json::to_string(&NameDictionaryValue {
+            name: name.to_string(),
+        })
+        .unwrap();
+        let request = build_row_insert_request(
+            EntryType::NameDictionary,
+            Op::Upsert,
+            id.to_string().as_bytes(),
+            value.as_bytes(),
+            util::current_time_millis(),
+        );
+        self.table
+            .insert(request)
+            .await
+            .context(MigrateSystemCatalogSnafu)?;
+        Ok(id)
+    }
+
+    /// Reads the full name dictionary, mapping interned id to name.
+    async fn dictionary(&self) -> Result<HashMap<u32, String>> {
+        let rows = self.raw_rows().await?;
+        let mut dictionary = HashMap::new();
+        for row in reconcile(rows) {
+            if row.entry_type != EntryType::NameDictionary as u8 {
+                continue;
+            }
+            if let Entry::NameDictionary(e) =
+                decode_system_catalog(Some(row.entry_type), Some(&row.key), row.value.as_deref())?
+            {
+                dictionary.insert(e.id, e.name);
+            }
         }
+        Ok(dictionary)
+    }
+
+    /// Reads every raw row in the catalog table, without any MVCC
+    /// reconciliation.
+    async fn raw_rows(&self) -> Result<Vec<RawRow>> {
+        scan_raw_rows(&self.table).await
     }
 
-    /// Create a stream of all entries inside system catalog table
-    pub async fn records(&self) -> Result<SendableRecordBatchStream> {
-        let full_projection = None;
-        let stream = self.table.scan(&full_projection, &[], None).await.unwrap();
-        Ok(stream)
+    /// Physically deletes every superseded or tombstoned row, leaving only
+    /// the reconciled, live row per `(entry_type, key)` that [Self::records]
+    /// would already have surfaced logically.
+    ///
+    /// Table rows are exempt: unlike other entry types, older table
+    /// versions are not superseded garbage but intentional schema-evolution
+    /// history (see [TableEntry::history]), so they are left untouched.
+    ///
+    /// Also runs automatically in the background; see
+    /// [Self::spawn_background_compaction].
+    pub async fn compact(&self) -> Result<()> {
+        compact_table(&self.table).await
+    }
+
+    /// Registers a catalog backed by an external system (e.g. an Iceberg
+    /// REST catalog) under `catalog_name`. Unlike a native catalog, its
+    /// tables are not materialized as rows here — [Self::external_tables]
+    /// enumerates them lazily through the provider instead.
+    pub async fn register_external_catalog(
+        &self,
+        catalog_name: &str,
+        provider: ExternalCatalogValue,
+    ) -> Result<()> {
+        let value = serde_json::to_string(&provider).unwrap();
+        let request = build_row_insert_request(
+            EntryType::ExternalCatalog,
+            Op::Upsert,
+            catalog_name.as_bytes(),
+            value.as_bytes(),
+            util::current_time_millis(),
+        );
+        self.table
+            .insert(request)
+            .await
+            .context(MigrateSystemCatalogSnafu)?;
+        Ok(())
+    }
+
+    /// Enumerates the tables of every registered external catalog, keyed by
+    /// catalog name, by instantiating each [CatalogProvider] and querying it
+    /// directly rather than reading table rows out of this table.
+    ///
+    /// Providers are queried concurrently, so one slow or unreachable
+    /// endpoint only bounds its own catalog's listing time (via the
+    /// provider's own request timeout, e.g.
+    /// [IcebergRestCatalogProvider::REQUEST_TIMEOUT]) instead of blocking
+    /// every other registered catalog behind it.
+    pub async fn external_tables(&self) -> Result<HashMap<String, Vec<String>>> {
+        let rows = self.raw_rows().await?;
+        let descriptors = reconcile(rows)
+            .into_iter()
+            .filter(|row| row.entry_type == EntryType::ExternalCatalog as u8)
+            .map(|row| {
+                let catalog_name = String::from_utf8_lossy(&row.key).to_string();
+                let value = row.value.context(EmptyValueSnafu)?;
+                let descriptor: ExternalCatalogValue =
+                    serde_json::from_slice(&value).context(ValueDeserializeSnafu)?;
+                Ok((catalog_name, descriptor))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        futures::future::join_all(descriptors.into_iter().map(
+            |(catalog_name, descriptor)| async move {
+                let tables = descriptor.build_provider().list_tables().await?;
+                Ok::<_, Error>((catalog_name, tables))
+            },
+        ))
+        .await
+        .into_iter()
+        .collect::<Result<HashMap<_, _>>>()
+    }
+}
+
+/// A single undecoded row, as read straight off the system catalog table.
+struct RawRow {
+    entry_type: u8,
+    key: Vec<u8>,
+    timestamp: i64,
+    op: Op,
+    value: Option<Vec<u8>>,
+}
+
+impl RawRow {
+    fn from_values(row: &[Value]) -> Result<Self> {
+        let entry_type = row[ENTRY_TYPE_INDEX]
+            .as_u8()
+            .context(InvalidKeySnafu { key: None })?;
+        let key = match &row[KEY_INDEX] {
+            Value::Binary(k) => k.to_vec(),
+            _ => return InvalidKeySnafu { key: None }.fail(),
+        };
+        let timestamp = match &row[TIMESTAMP_INDEX] {
+            Value::Timestamp(ts) => ts.value(),
+            _ => return InvalidKeySnafu { key: None }.fail(),
+        };
+        let op = match row[OP_INDEX].as_u8() {
+            Some(op) if op == Op::Tombstone as u8 => Op::Tombstone,
+            _ => Op::Upsert,
+        };
+        let value = match &row[VALUE_INDEX] {
+            Value::Binary(v) if !v.is_empty() => Some(v.to_vec()),
+            _ => None,
+        };
+        Ok(Self {
+            entry_type,
+            key,
+            timestamp,
+            op,
+            value,
+        })
+    }
+}
+
+/// Reads every raw row out of `table`, without any MVCC reconciliation. A
+/// free function (rather than a [SystemCatalogTable] method) so that the
+/// background compaction task spawned by
+/// [SystemCatalogTable::spawn_background_compaction] can run against a
+/// cloned [TableRef] without holding a reference to the table object itself.
+async fn scan_raw_rows(table: &TableRef) -> Result<Vec<RawRow>> {
+    let full_projection = None;
+    let mut stream = table.scan(&full_projection, &[], None).await.unwrap();
+    let mut rows = Vec::new();
+    while let Some(batch) = stream.try_next().await.context(MigrateSystemCatalogSnafu)? {
+        for row in row_values(&batch) {
+            rows.push(RawRow::from_values(&row)?);
+        }
+    }
+    Ok(rows)
+}
+
+/// Physically rewrites `table` to contain only the reconciled, live row per
+/// `(entry_type, key)`, other than table rows (see
+/// [SystemCatalogTable::compact]), by deleting every row `reconcile` would
+/// not have kept: superseded older versions, and the tombstone itself once
+/// it is the group's latest row (there is no live value left to preserve).
+/// `timestamp` is part of the primary key, so the live survivor is already
+/// stored correctly and is left untouched — re-inserting it would only mint
+/// a duplicate row under a fresh timestamp instead of compacting anything.
+async fn compact_table(table: &TableRef) -> Result<()> {
+    let rows = scan_raw_rows(table).await?;
+    let mut groups: HashMap<(u8, Vec<u8>), Vec<RawRow>> = HashMap::new();
+    for row in rows {
+        if row.entry_type == EntryType::Table as u8 {
+            continue;
+        }
+        groups.entry((row.entry_type, row.key.clone())).or_default().push(row);
+    }
+
+    for (_, versions) in groups {
+        for row in rows_to_compact(versions) {
+            let request = build_row_delete_request(row.entry_type, &row.key, row.timestamp);
+            table.delete(request).await.context(MigrateSystemCatalogSnafu)?;
+        }
+    }
+    Ok(())
+}
+
+/// Picks, out of every raw row sharing one `(entry_type, key)` group, the
+/// ones [compact_table] should physically delete: every superseded older
+/// version, plus the latest row itself once it is a tombstone (there is no
+/// live value left under that key to keep).
+fn rows_to_compact(mut versions: Vec<RawRow>) -> Vec<RawRow> {
+    versions.sort_by_key(|row| row.timestamp);
+    let latest = versions
+        .pop()
+        .expect("a group always has at least one row");
+    let mut garbage = versions;
+    if latest.op == Op::Tombstone {
+        garbage.push(latest);
+    }
+    garbage
+}
+
+/// Groups rows by `(entry_type, key)`, keeps only the row with the highest
+/// `timestamp` per key, and drops keys whose surviving row is a tombstone.
+fn reconcile(rows: Vec<RawRow>) -> Vec<RawRow> {
+    let mut latest: HashMap<(u8, Vec<u8>), RawRow> = HashMap::new();
+    for row in rows {
+        let pk = (row.entry_type, row.key.clone());
+        match latest.get(&pk) {
+            Some(existing) if existing.timestamp >= row.timestamp => {}
+            _ => {
+                latest.insert(pk, row);
+            }
+        }
+    }
+    latest
+        .into_values()
+        .filter(|row| row.op != Op::Tombstone)
+        .collect()
+}
+
+/// Resolves a decoded [Entry::Table]'s `catalog_name`/`schema_name` fields
+/// from dictionary ids back to names, if they are dictionary-encoded (i.e.
+/// tagged with [DICTIONARY_ID_PREFIX]). Components that were never
+/// dictionary-encoded — including literal names that happen to be all-digits,
+/// e.g. a numeric multi-tenant catalog id — carry no tag and pass through
+/// unchanged.
+fn resolve_dictionary_ids(mut entry: Entry, dictionary: &HashMap<u32, String>) -> Entry {
+    if let Entry::Table(table) = &mut entry {
+        if let Some(name) = dictionary_lookup(&table.catalog_name, dictionary) {
+            table.catalog_name = name;
+        }
+        if let Some(name) = dictionary_lookup(&table.schema_name, dictionary) {
+            table.schema_name = name;
+        }
+    }
+    entry
+}
+
+/// Resolves a single key component to its interned name, if `component` is
+/// tagged with [DICTIONARY_ID_PREFIX] and its id is present in `dictionary`.
+fn dictionary_lookup(component: &str, dictionary: &HashMap<u32, String>) -> Option<String> {
+    let id: u32 = component.strip_prefix(DICTIONARY_ID_PREFIX)?.parse().ok()?;
+    dictionary.get(&id).cloned()
+}
+
+/// Decodes every version of a single table's `(entry_type, key)` group into a
+/// [TableEntry], keeping the highest-timestamp version as the current value
+/// and attaching the rest, oldest first, as [TableEntry::history]. Returns
+/// `Ok(None)` if the latest version is a tombstone, i.e. the table is
+/// currently dropped.
+fn decode_table_versions(mut versions: Vec<RawRow>) -> Result<Option<TableEntry>> {
+    versions.sort_by_key(|row| row.timestamp);
+    let current_row = versions
+        .pop()
+        .expect("a table version group always has at least one row");
+    if current_row.op == Op::Tombstone {
+        return Ok(None);
+    }
+
+    let mut current = match decode_system_catalog(
+        Some(current_row.entry_type),
+        Some(&current_row.key),
+        current_row.value.as_deref(),
+    )? {
+        Entry::Table(table) => table,
+        _ => unreachable!("EntryType::Table always decodes into Entry::Table"),
+    };
+
+    current.history = versions
+        .into_iter()
+        .map(|row| {
+            let value = row.value.context(EmptyValueSnafu)?;
+            serde_json::from_slice(&value).context(ValueDeserializeSnafu)
+        })
+        .collect::<Result<_>>()?;
+    Ok(Some(current))
+}
+
+/// Extracts each row of `batch` as a vector of [Value], indexed the same way
+/// as the system catalog's columns (see [build_system_catalog_schema]).
+/// Columns are matched by name, so a table whose `op` column was appended by
+/// [SystemCatalogTable::ensure_op_column] still lines up; a column the batch
+/// lacks reads as [Value::Null].
+fn row_values(batch: &RecordBatch) -> Vec<Vec<Value>> {
+    let columns: Vec<Option<usize>> = build_system_catalog_schema()
+        .column_schemas()
+        .iter()
+        .map(|column| batch.schema.column_index_by_name(&column.name))
+        .collect();
+    (0..batch.num_rows())
+        .map(|row| {
+            columns
+                .iter()
+                .map(|col| col.map_or(Value::Null, |col| batch.column(col).get(row)))
+                .collect()
+        })
+        .collect()
+}
+
+trait ValueExt {
+    fn as_u8(&self) -> Option<u8>;
+}
+
+impl ValueExt for Value {
+    fn as_u8(&self) -> Option<u8> {
+        match self {
+            Value::UInt8(v) => Some(*v),
+            _ => None,
+        }
     }
 }
 
 /// Build system catalog table schema.
-/// A system catalog table consists of 6 columns, namely
+/// A system catalog table consists of 7 columns, namely
 /// - entry_type: type of entry in current row, can be any variant of [EntryType].
 /// - key: a binary encoded key of entry, differs according to different entry type.
-/// - timestamp: currently not used.
+/// - timestamp: a monotonically increasing write timestamp, part of the primary key.
+///   Together with `op` this gives the table MVCC semantics: the row with the
+///   highest timestamp for a given `(entry_type, key)` is the current value,
+///   and a [Op::Tombstone] row marks that key as deleted.
 /// - value: JSON-encoded value of entry's metadata.
+/// - op: marks whether this row is an upsert or a tombstone, see [Op].
 /// - gmt_created: create time of this metadata.
 /// - gmt_modified: last updated time of this metadata.
 fn build_system_catalog_schema() -> Schema {
@@ -138,6 +949,7 @@ fn build_system_catalog_schema() -> Schema {
             ConcreteDataType::binary_datatype(),
             false,
         ),
+        ColumnSchema::new("op".to_string(), ConcreteDataType::uint8_datatype(), false),
         ColumnSchema::new(
             "gmt_created".to_string(),
             ConcreteDataType::timestamp_millis_datatype(),
@@ -158,45 +970,60 @@ fn build_system_catalog_schema() -> Schema {
         .unwrap()
 }
 
-pub fn build_table_insert_request(full_table_name: String, table_id: TableId) -> InsertRequest {
-    let mut columns_values = HashMap::with_capacity(6);
+/// Marker carried by every row, telling whether it is a live value or a
+/// tombstone left behind by a delete.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Op {
+    Upsert = 0,
+    Tombstone = 1,
+}
+
+/// Builds the insert request for a single system catalog row. Every helper
+/// below that builds an [InsertRequest] funnels through this so that the MVCC
+/// columns (`timestamp`, `op`) are always populated consistently.
+fn build_row_insert_request(
+    entry_type: EntryType,
+    op: Op,
+    key: &[u8],
+    value: &[u8],
+    timestamp_millis: i64,
+) -> InsertRequest {
+    let mut columns_values = HashMap::with_capacity(7);
     columns_values.insert(
         "entry_type".to_string(),
-        Arc::new(UInt8Vector::from_slice(&[EntryType::Table as u8])) as _,
+        Arc::new(UInt8Vector::from_slice(&[entry_type as u8])) as _,
     );
 
     columns_values.insert(
         "key".to_string(),
-        Arc::new(BinaryVector::from_slice(&[full_table_name.as_bytes()])) as _,
+        Arc::new(BinaryVector::from_slice(&[key])) as _,
     );
 
-    // Timestamp in key part is intentionally left to 0
     columns_values.insert(
         "timestamp".to_string(),
-        Arc::new(TimestampVector::from_slice(&[Timestamp::from_millis(0)])) as _,
+        Arc::new(TimestampVector::from_slice(&[Timestamp::from_millis(
+            timestamp_millis,
+        )])) as _,
     );
 
     columns_values.insert(
         "value".to_string(),
-        Arc::new(BinaryVector::from_slice(&[serde_json::to_string(
-            &TableEntryValue { table_id },
-        )
-        .unwrap()
-        .as_bytes()])) as _,
+        Arc::new(BinaryVector::from_slice(&[value])) as _,
     );
 
     columns_values.insert(
-        "gmt_created".to_string(),
-        Arc::new(TimestampVector::from_slice(&[Timestamp::from_millis(
-            util::current_time_millis(),
-        )])) as _,
+        "op".to_string(),
+        Arc::new(UInt8Vector::from_slice(&[op as u8])) as _,
     );
 
+    let now = Timestamp::from_millis(util::current_time_millis());
+    columns_values.insert(
+        "gmt_created".to_string(),
+        Arc::new(TimestampVector::from_slice(&[now])) as _,
+    );
     columns_values.insert(
         "gmt_modified".to_string(),
-        Arc::new(TimestampVector::from_slice(&[Timestamp::from_millis(
-            util::current_time_millis(),
-        )])) as _,
+        Arc::new(TimestampVector::from_slice(&[now])) as _,
     );
 
     InsertRequest {
@@ -205,6 +1032,146 @@ pub fn build_table_insert_request(full_table_name: String, table_id: TableId) ->
     }
 }
 
+/// Builds the delete request that physically removes a single raw row,
+/// identified by its full primary key (`entry_type`, `key`, `timestamp`).
+/// Used only by [compact_table] to drop superseded/tombstoned rows; a normal
+/// delete of a live entry is a tombstone *insert* (see
+/// [build_table_delete_request]), not this.
+fn build_row_delete_request(entry_type: u8, key: &[u8], timestamp_millis: i64) -> DeleteRequest {
+    let mut key_column_values = HashMap::with_capacity(3);
+    key_column_values.insert(
+        "entry_type".to_string(),
+        Arc::new(UInt8Vector::from_slice(&[entry_type])) as _,
+    );
+    key_column_values.insert(
+        "key".to_string(),
+        Arc::new(BinaryVector::from_slice(&[key])) as _,
+    );
+    key_column_values.insert(
+        "timestamp".to_string(),
+        Arc::new(TimestampVector::from_slice(&[Timestamp::from_millis(
+            timestamp_millis,
+        )])) as _,
+    );
+    DeleteRequest {
+        table_name: SYSTEM_CATALOG_TABLE_NAME.to_string(),
+        key_column_values,
+    }
+}
+
+pub fn build_table_insert_request(full_table_name: String, meta: &TableEntryValue) -> InsertRequest {
+    build_table_insert_request_at(full_table_name, meta, util::current_time_millis())
+}
+
+/// As [build_table_insert_request], but with an explicit row timestamp
+/// instead of the current time. See
+/// [SystemCatalogTable::insert_table_version_at].
+fn build_table_insert_request_at(
+    full_table_name: String,
+    meta: &TableEntryValue,
+    timestamp_millis: i64,
+) -> InsertRequest {
+    let value = serde_json::to_string(meta).unwrap();
+    build_row_insert_request(
+        EntryType::Table,
+        Op::Upsert,
+        full_table_name.as_bytes(),
+        value.as_bytes(),
+        timestamp_millis,
+    )
+}
+
+/// Builds the insert request that marks a table as dropped, by writing a
+/// tombstone row sharing the same `(entry_type, key)` primary key as the
+/// table's upsert rows but with a fresher timestamp. See [decode_table_versions].
+pub fn build_table_delete_request(full_table_name: String) -> InsertRequest {
+    build_row_insert_request(
+        EntryType::Table,
+        Op::Tombstone,
+        full_table_name.as_bytes(),
+        &[],
+        util::current_time_millis(),
+    )
+}
+
+/// Key of the single row that stores the catalog format version.
+const VERSION_KEY: &str = "__version__";
+
+/// Builds the insert request that writes (or overwrites) the catalog format
+/// version row.
+fn build_version_insert_request(version: u32) -> InsertRequest {
+    let value = serde_json::to_string(&VersionEntry { version }).unwrap();
+    build_row_insert_request(
+        EntryType::Version,
+        Op::Upsert,
+        VERSION_KEY.as_bytes(),
+        value.as_bytes(),
+        util::current_time_millis(),
+    )
+}
+
+/// Builds the insert request that writes a decoded [Entry] back to the
+/// system catalog table, e.g. when re-inserting rows rewritten by a
+/// [Migration].
+fn build_entry_insert_request(entry: &Entry) -> InsertRequest {
+    match entry {
+        Entry::Catalog(e) => build_key_only_insert_request(EntryType::Catalog, &e.catalog_name),
+        Entry::Schema(e) => build_key_only_insert_request(
+            EntryType::Schema,
+            &format!("{}.{}", e.catalog_name, e.schema_name),
+        ),
+        // Table entries normally go through `SystemCatalogTable::insert_table`
+        // instead, which dictionary-encodes the key; this literal-key form
+        // only exists so the match below is exhaustive.
+        Entry::Table(e) => build_table_insert_request(
+            format!("{}.{}.{}", e.catalog_name, e.schema_name, e.table_name),
+            &TableEntryValue {
+                table_id: e.table_id,
+                schema_version: e.schema_version,
+                schema: e.schema.clone(),
+                primary_key_indices: e.primary_key_indices.clone(),
+                table_options: e.table_options.clone(),
+            },
+        ),
+        Entry::Version(e) => build_version_insert_request(e.version),
+        Entry::ExternalCatalog(e) => {
+            let value = serde_json::to_string(&e.provider).unwrap();
+            build_row_insert_request(
+                EntryType::ExternalCatalog,
+                Op::Upsert,
+                e.catalog_name.as_bytes(),
+                value.as_bytes(),
+                util::current_time_millis(),
+            )
+        }
+        Entry::NameDictionary(e) => {
+            let value = serde_json::to_string(&NameDictionaryValue {
+                name: e.name.clone(),
+            })
+            .unwrap();
+            build_row_insert_request(
+                EntryType::NameDictionary,
+                Op::Upsert,
+                e.id.to_string().as_bytes(),
+                value.as_bytes(),
+                util::current_time_millis(),
+            )
+        }
+    }
+}
+
+/// Builds the insert request for an entry type whose value column is unused,
+/// i.e. [EntryType::Catalog] and [EntryType::Schema].
+fn build_key_only_insert_request(entry_type: EntryType, key: &str) -> InsertRequest {
+    build_row_insert_request(
+        entry_type,
+        Op::Upsert,
+        key.as_bytes(),
+        &[],
+        util::current_time_millis(),
+    )
+}
+
 pub fn decode_system_catalog(
     entry_type: Option<u8>,
     key: Option<&[u8]>,
@@ -218,6 +1185,14 @@ pub fn decode_system_catalog(
     let key = String::from_utf8_lossy(key.context(InvalidKeySnafu { key: None })?);
 
     match EntryType::try_from(entry_type)? {
+        EntryType::Version => {
+            // As for version entry, the key is unused and the value is a
+            // JSON string with format: `{"version": <u32>}`.
+            let value = value.context(EmptyValueSnafu)?;
+            let version: VersionEntry =
+                serde_json::from_slice(value).context(ValueDeserializeSnafu)?;
+            Ok(Entry::Version(version))
+        }
         EntryType::Catalog => {
             // As for catalog entry, the key is a string with format: `<catalog_name>`
             // and the value is current not used.
@@ -242,7 +1217,9 @@ pub fn decode_system_catalog(
 
         EntryType::Table => {
             // As for table entry, the key is a string with format: `<catalog_name>.<schema_name>.<table_name>`
-            // and the value is a JSON string with format: `{"table_id": <table_id>}`
+            // and the value is a JSON-encoded `TableEntryValue` carrying the
+            // table's id, schema, primary key and options as of this
+            // particular `schema_version`.
             let table_parts = key.split('.').collect::<Vec<_>>();
             ensure!(
                 table_parts.len() >= 3,
@@ -259,6 +1236,44 @@ pub fn decode_system_catalog(
                 schema_name: table_parts[1].to_string(),
                 table_name: table_parts[2].to_string(),
                 table_id: table_meta.table_id,
+                schema_version: table_meta.schema_version,
+                schema: table_meta.schema,
+                primary_key_indices: table_meta.primary_key_indices,
+                table_options: table_meta.table_options,
+                history: Vec::new(),
+            }))
+        }
+
+        EntryType::ExternalCatalog => {
+            // As for external catalog entry, the key is a string with format:
+            // `<catalog_name>` and the value is a JSON-encoded
+            // [ExternalCatalogValue] describing the backing provider.
+            let catalog_name = key.to_string();
+            let value = value.context(EmptyValueSnafu)?;
+            let provider: ExternalCatalogValue =
+                serde_json::from_slice(value).context(ValueDeserializeSnafu)?;
+            Ok(Entry::ExternalCatalog(ExternalCatalogEntry {
+                catalog_name,
+                provider,
+            }))
+        }
+
+        EntryType::NameDictionary => {
+            // As for name dictionary entry, the key is the interned id
+            // encoded as a decimal string and the value is a JSON string
+            // with format: `{"name": <original name>}`.
+            let id: u32 = key
+                .parse()
+                .ok()
+                .context(InvalidKeySnafu {
+                    key: Some(key.to_string()),
+                })?;
+            let value = value.context(EmptyValueSnafu)?;
+            let dictionary_value: NameDictionaryValue =
+                serde_json::from_slice(value).context(ValueDeserializeSnafu)?;
+            Ok(Entry::NameDictionary(NameDictionaryEntry {
+                id,
+                name: dictionary_value.name,
             }))
         }
     }
@@ -266,9 +1281,12 @@ pub fn decode_system_catalog(
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum EntryType {
+    Version = 0,
     Catalog = 1,
     Schema = 2,
     Table = 3,
+    ExternalCatalog = 4,
+    NameDictionary = 5,
 }
 
 impl TryFrom<u8> for EntryType {
@@ -276,9 +1294,12 @@ impl TryFrom<u8> for EntryType {
 
     fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
         match value {
+            b if b == Self::Version as u8 => Ok(Self::Version),
             b if b == Self::Catalog as u8 => Ok(Self::Catalog),
             b if b == Self::Schema as u8 => Ok(Self::Schema),
             b if b == Self::Table as u8 => Ok(Self::Table),
+            b if b == Self::ExternalCatalog as u8 => Ok(Self::ExternalCatalog),
+            b if b == Self::NameDictionary as u8 => Ok(Self::NameDictionary),
             b => InvalidEntryTypeSnafu {
                 entry_type: Some(b),
             }
@@ -287,11 +1308,19 @@ impl TryFrom<u8> for EntryType {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq)]
 pub enum Entry {
+    Version(VersionEntry),
     Catalog(CatalogEntry),
     Schema(SchemaEntry),
     Table(TableEntry),
+    ExternalCatalog(ExternalCatalogEntry),
+    NameDictionary(NameDictionaryEntry),
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VersionEntry {
+    pub version: u32,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -305,17 +1334,161 @@ pub struct SchemaEntry {
     pub schema_name: String,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TableEntry {
     pub catalog_name: String,
     pub schema_name: String,
     pub table_name: String,
     pub table_id: TableId,
+    /// Monotonically increasing version, bumped by each
+    /// [SystemCatalogTable::alter_table]. 0 for a row written before this
+    /// field existed.
+    pub schema_version: u32,
+    /// `None` for a table row written before schema persistence was added;
+    /// such tables still need their schema looked up through the table
+    /// engine, exactly as every table did before this field existed.
+    pub schema: Option<SchemaRef>,
+    pub primary_key_indices: Vec<usize>,
+    pub table_options: HashMap<String, String>,
+    /// Earlier schema snapshots of this table, oldest first, written by
+    /// [SystemCatalogTable::insert_table] and previous calls to
+    /// [SystemCatalogTable::alter_table]. Does not include
+    /// the current version captured by the fields above.
+    pub history: Vec<TableEntryValue>,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+/// The value column of a table row: a single schema snapshot, identified by
+/// `schema_version`. The catalog keeps one of these for the table's creation
+/// and one per ALTER, forming the
+/// ordered history exposed through [TableEntry::history].
+///
+/// Every field but `table_id` carries `#[serde(default)]`, so a row written
+/// before this value was enriched (i.e. just `{"table_id": ...}`) still
+/// decodes, with `schema: None` signaling that its schema is not known to
+/// the catalog and must still come from the table engine.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TableEntryValue {
     pub table_id: TableId,
+    #[serde(default)]
+    pub schema_version: u32,
+    #[serde(default)]
+    pub schema: Option<SchemaRef>,
+    #[serde(default)]
+    pub primary_key_indices: Vec<usize>,
+    #[serde(default)]
+    pub table_options: HashMap<String, String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExternalCatalogEntry {
+    pub catalog_name: String,
+    pub provider: ExternalCatalogValue,
+}
+
+/// A `(id, name)` pair from the catalog's name dictionary, used to
+/// dictionary-encode table keys. See [SystemCatalogTable::insert_table].
+#[derive(Debug, PartialEq, Eq)]
+pub struct NameDictionaryEntry {
+    pub id: u32,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+struct NameDictionaryValue {
+    name: String,
+}
+
+/// Descriptor of the provider backing an [EntryType::ExternalCatalog], as
+/// stored in the `value` column. Adding a new external catalog backend means
+/// adding a variant here plus a [CatalogProvider] implementation.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ExternalCatalogValue {
+    IcebergRest(IcebergRestConfig),
+}
+
+impl ExternalCatalogValue {
+    /// Instantiates the [CatalogProvider] described by this descriptor.
+    pub fn build_provider(&self) -> Arc<dyn CatalogProvider> {
+        match self {
+            ExternalCatalogValue::IcebergRest(config) => {
+                Arc::new(IcebergRestCatalogProvider::new(config.clone()))
+            }
+        }
+    }
+}
+
+/// A catalog or schema backed by an external metadata system, queried
+/// on demand instead of having its tables materialized as rows in the
+/// system catalog table.
+#[async_trait::async_trait]
+pub trait CatalogProvider: Send + Sync {
+    /// Lists the names of the tables this provider currently exposes.
+    async fn list_tables(&self) -> Result<Vec<String>>;
+}
+
+/// Connection details for an [Iceberg REST catalog](https://iceberg.apache.org/rest-catalog-spec/).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IcebergRestConfig {
+    /// Base URL of the REST catalog server, e.g. `http://localhost:8181`.
+    pub endpoint: String,
+    pub warehouse: String,
+    pub namespace: String,
+}
+
+pub struct IcebergRestCatalogProvider {
+    config: IcebergRestConfig,
+    client: reqwest::Client,
+}
+
+impl IcebergRestCatalogProvider {
+    /// Bounded timeout for requests to the Iceberg REST catalog, so a slow
+    /// or unreachable endpoint cannot hang [Self::list_tables] (and, through
+    /// it, [SystemCatalogTable::external_tables]) indefinitely.
+    const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+    pub fn new(config: IcebergRestConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Self::REQUEST_TIMEOUT)
+            .build()
+            .expect("building the Iceberg REST HTTP client should never fail");
+        Self { config, client }
+    }
+}
+
+#[async_trait::async_trait]
+impl CatalogProvider for IcebergRestCatalogProvider {
+    async fn list_tables(&self) -> Result<Vec<String>> {
+        let url = format!(
+            "{}/v1/namespaces/{}/tables",
+            self.config.endpoint.trim_end_matches('/'),
+            self.config.namespace
+        );
+        let response: IcebergListTablesResponse = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context(IcebergRestSnafu)?
+            .json()
+            .await
+            .context(IcebergRestSnafu)?;
+        Ok(response
+            .identifiers
+            .into_iter()
+            .map(|identifier| identifier.name)
+            .collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct IcebergListTablesResponse {
+    identifiers: Vec<IcebergTableIdentifier>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IcebergTableIdentifier {
+    name: String,
 }
 
 #[cfg(test)]
@@ -354,12 +1527,33 @@ mod tests {
         }
     }
 
+    fn test_schema() -> SchemaRef {
+        Arc::new(
+            SchemaBuilder::try_from(vec![ColumnSchema::new(
+                "id".to_string(),
+                ConcreteDataType::uint64_datatype(),
+                false,
+            )])
+            .unwrap()
+            .build()
+            .unwrap(),
+        )
+    }
+
     #[test]
     pub fn test_decode_table() {
+        let meta = TableEntryValue {
+            table_id: 42,
+            schema_version: 0,
+            schema: Some(test_schema()),
+            primary_key_indices: vec![0],
+            table_options: HashMap::new(),
+        };
+        let value = serde_json::to_string(&meta).unwrap();
         let entry = decode_system_catalog(
             Some(EntryType::Table as u8),
             Some("some_catalog.some_schema.some_table".as_bytes()),
-            Some("{\"table_id\":42}".as_bytes()),
+            Some(value.as_bytes()),
         )
         .unwrap();
 
@@ -368,6 +1562,33 @@ mod tests {
             assert_eq!("some_schema", e.schema_name);
             assert_eq!("some_table", e.table_name);
             assert_eq!(42, e.table_id);
+            assert_eq!(0, e.schema_version);
+            assert_eq!(vec![0], e.primary_key_indices);
+            assert!(e.history.is_empty());
+        } else {
+            panic!("Unexpected type: {:?}", entry);
+        }
+    }
+
+    #[test]
+    pub fn test_decode_table_legacy_value_defaults_new_fields() {
+        // A table row written before this request, carrying only the field
+        // `TableEntryValue` originally had. Must still decode, or every
+        // table written before the upgrade would break `migrate_if_needed`
+        // on open.
+        let entry = decode_system_catalog(
+            Some(EntryType::Table as u8),
+            Some("some_catalog.some_schema.some_table".as_bytes()),
+            Some("{\"table_id\":42}".as_bytes()),
+        )
+        .unwrap();
+
+        if let Entry::Table(e) = entry {
+            assert_eq!(42, e.table_id);
+            assert_eq!(0, e.schema_version);
+            assert!(e.schema.is_none());
+            assert!(e.primary_key_indices.is_empty());
+            assert!(e.table_options.is_empty());
         } else {
             panic!("Unexpected type: {:?}", entry);
         }
@@ -386,9 +1607,268 @@ mod tests {
 
     #[test]
     pub fn test_entry_type() {
+        assert_eq!(EntryType::Version, EntryType::try_from(0).unwrap());
         assert_eq!(EntryType::Catalog, EntryType::try_from(1).unwrap());
         assert_eq!(EntryType::Schema, EntryType::try_from(2).unwrap());
         assert_eq!(EntryType::Table, EntryType::try_from(3).unwrap());
-        assert!(EntryType::try_from(4).is_err());
+        assert_eq!(EntryType::ExternalCatalog, EntryType::try_from(4).unwrap());
+        assert_eq!(EntryType::NameDictionary, EntryType::try_from(5).unwrap());
+        assert!(EntryType::try_from(6).is_err());
+    }
+
+    #[test]
+    pub fn test_decode_external_catalog_entry() {
+        let value = serde_json::to_string(&ExternalCatalogValue::IcebergRest(IcebergRestConfig {
+            endpoint: "http://localhost:8181".to_string(),
+            warehouse: "warehouse".to_string(),
+            namespace: "ns".to_string(),
+        }))
+        .unwrap();
+        let entry = decode_system_catalog(
+            Some(EntryType::ExternalCatalog as u8),
+            Some("iceberg_catalog".as_bytes()),
+            Some(value.as_bytes()),
+        )
+        .unwrap();
+
+        if let Entry::ExternalCatalog(e) = entry {
+            assert_eq!("iceberg_catalog", e.catalog_name);
+            assert_eq!(
+                ExternalCatalogValue::IcebergRest(IcebergRestConfig {
+                    endpoint: "http://localhost:8181".to_string(),
+                    warehouse: "warehouse".to_string(),
+                    namespace: "ns".to_string(),
+                }),
+                e.provider
+            );
+        } else {
+            panic!("Unexpected type: {:?}", entry);
+        }
+    }
+
+    #[test]
+    pub fn test_decode_name_dictionary_entry() {
+        let entry = decode_system_catalog(
+            Some(EntryType::NameDictionary as u8),
+            Some("3".as_bytes()),
+            Some("{\"name\":\"greptime\"}".as_bytes()),
+        )
+        .unwrap();
+
+        if let Entry::NameDictionary(e) = entry {
+            assert_eq!(3, e.id);
+            assert_eq!("greptime", e.name);
+        } else {
+            panic!("Unexpected type: {:?}", entry);
+        }
+    }
+
+    #[test]
+    pub fn test_resolve_dictionary_ids() {
+        let mut dictionary = HashMap::new();
+        dictionary.insert(0, "greptime".to_string());
+        dictionary.insert(1, "public".to_string());
+
+        let entry = Entry::Table(TableEntry {
+            catalog_name: "$0".to_string(),
+            schema_name: "$1".to_string(),
+            table_name: "my_table".to_string(),
+            table_id: 42,
+            schema_version: 0,
+            schema: Some(test_schema()),
+            primary_key_indices: vec![0],
+            table_options: HashMap::new(),
+            history: Vec::new(),
+        });
+
+        if let Entry::Table(e) = resolve_dictionary_ids(entry, &dictionary) {
+            assert_eq!("greptime", e.catalog_name);
+            assert_eq!("public", e.schema_name);
+            assert_eq!("my_table", e.table_name);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    pub fn test_resolve_dictionary_ids_passes_through_unknown_names() {
+        let dictionary = HashMap::new();
+        let entry = Entry::Table(TableEntry {
+            catalog_name: "greptime".to_string(),
+            schema_name: "public".to_string(),
+            table_name: "my_table".to_string(),
+            table_id: 42,
+            schema_version: 0,
+            schema: Some(test_schema()),
+            primary_key_indices: vec![0],
+            table_options: HashMap::new(),
+            history: Vec::new(),
+        });
+
+        if let Entry::Table(e) = resolve_dictionary_ids(entry, &dictionary) {
+            assert_eq!("greptime", e.catalog_name);
+            assert_eq!("public", e.schema_name);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    pub fn test_resolve_dictionary_ids_passes_through_numeric_literal_name() {
+        // A literal catalog name that happens to be all-digits (e.g. a
+        // numeric multi-tenant id) must not be mistaken for a
+        // dictionary-encoded component just because it parses as a `u32`.
+        let mut dictionary = HashMap::new();
+        dictionary.insert(12345, "unrelated_tenant".to_string());
+
+        let entry = Entry::Table(TableEntry {
+            catalog_name: "12345".to_string(),
+            schema_name: "public".to_string(),
+            table_name: "my_table".to_string(),
+            table_id: 42,
+            schema_version: 0,
+            schema: Some(test_schema()),
+            primary_key_indices: vec![0],
+            table_options: HashMap::new(),
+            history: Vec::new(),
+        });
+
+        if let Entry::Table(e) = resolve_dictionary_ids(entry, &dictionary) {
+            assert_eq!("12345", e.catalog_name);
+            assert_eq!("public", e.schema_name);
+        } else {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    pub fn test_decode_version_entry() {
+        let entry = decode_system_catalog(
+            Some(EntryType::Version as u8),
+            Some(VERSION_KEY.as_bytes()),
+            Some("{\"version\":1}".as_bytes()),
+        )
+        .unwrap();
+
+        if let Entry::Version(e) = entry {
+            assert_eq!(1, e.version);
+        } else {
+            panic!("Unexpected type: {:?}", entry);
+        }
+    }
+
+    fn raw_row(entry_type: u8, key: &str, timestamp: i64, op: Op) -> RawRow {
+        RawRow {
+            entry_type,
+            key: key.as_bytes().to_vec(),
+            timestamp,
+            op,
+            value: None,
+        }
+    }
+
+    #[test]
+    pub fn test_reconcile_keeps_latest_version() {
+        let rows = vec![
+            raw_row(EntryType::Table as u8, "a.b.c", 1, Op::Upsert),
+            raw_row(EntryType::Table as u8, "a.b.c", 2, Op::Upsert),
+        ];
+        let survivors = reconcile(rows);
+        assert_eq!(1, survivors.len());
+        assert_eq!(2, survivors[0].timestamp);
+    }
+
+    #[test]
+    pub fn test_reconcile_drops_tombstoned_key() {
+        let rows = vec![
+            raw_row(EntryType::Table as u8, "a.b.c", 1, Op::Upsert),
+            raw_row(EntryType::Table as u8, "a.b.c", 2, Op::Tombstone),
+        ];
+        assert!(reconcile(rows).is_empty());
+    }
+
+    #[test]
+    pub fn test_rows_to_compact_deletes_only_superseded_versions() {
+        let rows = vec![
+            raw_row(EntryType::Catalog as u8, "a", 1, Op::Upsert),
+            raw_row(EntryType::Catalog as u8, "a", 2, Op::Upsert),
+            raw_row(EntryType::Catalog as u8, "a", 3, Op::Upsert),
+        ];
+
+        let garbage = rows_to_compact(rows);
+
+        // The latest (timestamp 3) row is the live survivor and must not be
+        // deleted, or a repeated compaction tick would eventually delete
+        // every row in the catalog.
+        assert_eq!(2, garbage.len());
+        assert!(garbage.iter().all(|row| row.timestamp != 3));
+    }
+
+    #[test]
+    pub fn test_rows_to_compact_deletes_whole_tombstoned_group() {
+        let rows = vec![
+            raw_row(EntryType::Catalog as u8, "a", 1, Op::Upsert),
+            raw_row(EntryType::Catalog as u8, "a", 2, Op::Tombstone),
+        ];
+
+        let garbage = rows_to_compact(rows);
+
+        assert_eq!(2, garbage.len());
+    }
+
+    fn raw_table_row(key: &str, timestamp: i64, op: Op, meta: &TableEntryValue) -> RawRow {
+        RawRow {
+            entry_type: EntryType::Table as u8,
+            key: key.as_bytes().to_vec(),
+            timestamp,
+            op,
+            value: Some(serde_json::to_vec(meta).unwrap()),
+        }
+    }
+
+    #[test]
+    pub fn test_decode_table_versions_builds_history() {
+        let v0 = TableEntryValue {
+            table_id: 42,
+            schema_version: 0,
+            schema: Some(test_schema()),
+            primary_key_indices: vec![0],
+            table_options: HashMap::new(),
+        };
+        let v1 = TableEntryValue {
+            schema_version: 1,
+            ..v0.clone()
+        };
+        let versions = vec![
+            raw_table_row("0.0.my_table", 1, Op::Upsert, &v0),
+            raw_table_row("0.0.my_table", 2, Op::Upsert, &v1),
+        ];
+
+        let table = decode_table_versions(versions).unwrap().unwrap();
+        assert_eq!(1, table.schema_version);
+        assert_eq!(vec![v0], table.history);
+    }
+
+    #[test]
+    pub fn test_decode_table_versions_drops_tombstoned_table() {
+        let v0 = TableEntryValue {
+            table_id: 42,
+            schema_version: 0,
+            schema: Some(test_schema()),
+            primary_key_indices: vec![0],
+            table_options: HashMap::new(),
+        };
+        let versions = vec![
+            raw_table_row("0.0.my_table", 1, Op::Upsert, &v0),
+            RawRow {
+                entry_type: EntryType::Table as u8,
+                key: "0.0.my_table".as_bytes().to_vec(),
+                timestamp: 2,
+                op: Op::Tombstone,
+                value: None,
+            },
+        ];
+
+        assert!(decode_table_versions(versions).unwrap().is_none());
     }
 }